@@ -1,16 +1,19 @@
 use axum::{
+    body::{to_bytes, Bytes},
     extract::{rejection::RawFormRejection, FromRequest, RawForm, Request},
     response::{IntoResponse, Response},
     Error, RequestExt,
 };
-use http::StatusCode;
-use serde::de::DeserializeOwned;
+use http::{header, HeaderValue, StatusCode};
+use http_body_util::LengthLimitError;
+use serde::{de::DeserializeOwned, Serialize};
 use std::fmt;
 
-/// Extractor that deserializes `application/x-www-form-urlencoded` requests
-/// into some type.
+/// Extractor/Response that deserializes/serializes `application/x-www-form-urlencoded` requests
+/// and responses.
 ///
-/// `T` is expected to implement [`serde::Deserialize`].
+/// `T` is expected to implement [`serde::Deserialize`] when used as an extractor, and
+/// [`serde::Serialize`] when used as a response.
 ///
 /// # Differences from `axum::extract::Form`
 ///
@@ -51,16 +54,320 @@ where
     type Rejection = FormRejection;
 
     async fn from_request(req: Request, _state: &S) -> Result<Self, Self::Rejection> {
+        let config = req
+            .extensions()
+            .get::<FormConfig>()
+            .cloned()
+            .unwrap_or_default();
+
+        if config.strict_content_type && !has_form_content_type(req.headers()) {
+            return Err(FormRejection::UnsupportedMediaType);
+        }
+
+        let headers = req.headers().clone();
+        let bytes = buffer_form_body(req, &config).await?;
+
+        deserialize_form(&bytes, &headers, &config).map(Self)
+    }
+}
+
+/// Reads the request body to completion, honoring `config`'s configured limit when set and
+/// otherwise going through [`RawForm`] so axum's own default body-limit extension (set up via
+/// `DefaultBodyLimit`) still applies, exactly as it does for [`Form`].
+async fn buffer_form_body(req: Request, config: &FormConfig) -> Result<Bytes, FormRejection> {
+    if let Some(limit) = config.limit {
+        to_bytes(req.into_body(), limit)
+            .await
+            .map_err(classify_buffer_error)
+    } else {
         let RawForm(bytes) = req
             .extract()
             .await
             .map_err(FormRejection::RawFormRejection)?;
+        Ok(bytes)
+    }
+}
+
+/// Decodes and deserializes an already-buffered form body.
+fn deserialize_form<T: DeserializeOwned>(
+    bytes: &[u8],
+    headers: &http::HeaderMap,
+    config: &FormConfig,
+) -> Result<T, FormRejection> {
+    let encoding = charset_from_content_type(headers)?;
+    let pairs = decode_form_pairs(bytes, encoding);
+    let deserializer = serde_html_form::Deserializer::new(pairs.into_iter());
+
+    serde_path_to_error::deserialize::<_, T>(deserializer).map_err(|err| {
+        let path = err.path().to_string();
+        FormRejection::FailedToDeserializeForm {
+            error: Error::new(err),
+            path,
+            structured: config.json_rejections,
+        }
+    })
+}
+
+/// Extractor that deserializes `application/x-www-form-urlencoded` requests into some type,
+/// yielding `None` when the body is empty and no `Content-Type` is set.
+///
+/// This is useful for endpoints where a form submission is optional, e.g. filters that should
+/// default to empty when the client sends no body at all. `Form` rejects such requests because
+/// an empty body is not valid `application/x-www-form-urlencoded` input. A malformed body that
+/// *is* present still surfaces as a [`FormRejection`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum_extra::extract::OptionalForm;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Filters {
+///     name: Option<String>,
+/// }
+///
+/// async fn search(OptionalForm(filters): OptionalForm<Filters>) {
+///     let filters = filters.unwrap_or(Filters { name: None });
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg(feature = "form")]
+pub struct OptionalForm<T>(pub Option<T>);
+
+axum_core::__impl_deref!(OptionalForm);
+
+impl<T, S> FromRequest<S> for OptionalForm<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = FormRejection;
+
+    async fn from_request(req: Request, _state: &S) -> Result<Self, Self::Rejection> {
+        let config = req
+            .extensions()
+            .get::<FormConfig>()
+            .cloned()
+            .unwrap_or_default();
+
+        let has_content_type = req.headers().contains_key(header::CONTENT_TYPE);
+        let headers = req.headers().clone();
 
-        let deserializer = serde_html_form::Deserializer::new(form_urlencoded::parse(&bytes));
+        // The size hint only reflects what the transport already knows (e.g. `Content-Length`);
+        // a chunked, zero-length body reports no hint at all. Buffer the body (honoring the same
+        // limit `Form` would use) so emptiness can be determined for every transfer encoding, not
+        // just the ones that advertise their length up front. `RawForm` rejects a non-`GET`
+        // request without a form `Content-Type` before reading its body, so such a body is read
+        // directly instead.
+        let bytes = if has_content_type || req.method() == http::Method::GET {
+            buffer_form_body(req, &config).await?
+        } else if let Some(limit) = config.limit {
+            to_bytes(req.into_body(), limit)
+                .await
+                .map_err(classify_buffer_error)?
+        } else {
+            req.extract::<Bytes, _>()
+                .await
+                .map_err(|err| FormRejection::RawFormRejection(err.into()))?
+        };
+
+        if bytes.is_empty() && !has_content_type {
+            return Ok(Self(None));
+        }
+
+        if config.strict_content_type && !has_form_content_type(&headers) {
+            return Err(FormRejection::UnsupportedMediaType);
+        }
 
-        serde_path_to_error::deserialize::<_, T>(deserializer)
-            .map(Self)
-            .map_err(|err| FormRejection::FailedToDeserializeForm(Error::new(err)))
+        deserialize_form(&bytes, &headers, &config).map(|value| Self(Some(value)))
+    }
+}
+
+/// Determines the [`&'static Encoding`](encoding_rs::Encoding) to use for the request body,
+/// based on the `charset` parameter of the `Content-Type` header. Defaults to UTF-8 when the
+/// header or parameter is absent.
+fn charset_from_content_type(
+    headers: &http::HeaderMap,
+) -> Result<&'static encoding_rs::Encoding, FormRejection> {
+    let Some(content_type) = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return Ok(encoding_rs::UTF_8);
+    };
+
+    let Ok(mime) = content_type.parse::<mime::Mime>() else {
+        return Ok(encoding_rs::UTF_8);
+    };
+
+    let Some(charset) = mime.get_param("charset") else {
+        return Ok(encoding_rs::UTF_8);
+    };
+
+    encoding_rs::Encoding::for_label(charset.as_str().as_bytes())
+        .ok_or_else(|| FormRejection::InvalidCharset(charset.as_str().to_owned()))
+}
+
+/// Splits a `application/x-www-form-urlencoded` body into `(key, value)` pairs.
+///
+/// Percent-decoding (and `+`-as-space decoding) happens first, on the raw wire bytes, to recover
+/// the charset-native byte sequence the client actually sent; only then is `encoding` applied to
+/// turn those bytes into UTF-8. Doing it in the other order would try to transcode the
+/// (all-ASCII) percent-encoded text itself, leaving any non-ASCII byte to be percent-decoded
+/// later and misinterpreted as UTF-8.
+fn decode_form_pairs(
+    bytes: &[u8],
+    encoding: &'static encoding_rs::Encoding,
+) -> Vec<(String, String)> {
+    bytes
+        .split(|&b| b == b'&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let mut parts = pair.splitn(2, |&b| b == b'=');
+            let key = parts.next().unwrap_or(&[]);
+            let value = parts.next().unwrap_or(&[]);
+            (
+                decode_form_component(key, encoding),
+                decode_form_component(value, encoding),
+            )
+        })
+        .collect()
+}
+
+/// Percent-decodes a single key/value component, then decodes the resulting bytes with
+/// `encoding`.
+fn decode_form_component(raw: &[u8], encoding: &'static encoding_rs::Encoding) -> String {
+    let with_spaces: Vec<u8> = raw
+        .iter()
+        .map(|&b| if b == b'+' { b' ' } else { b })
+        .collect();
+    let decoded_bytes: Vec<u8> = percent_encoding::percent_decode(&with_spaces).collect();
+    let (text, _, _) = encoding.decode(&decoded_bytes);
+    text.into_owned()
+}
+
+/// Returns `true` if the `Content-Type` header is present and its essence is
+/// `application/x-www-form-urlencoded`.
+fn has_form_content_type(headers: &http::HeaderMap) -> bool {
+    let Some(content_type) = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return false;
+    };
+
+    let Ok(mime) = content_type.parse::<mime::Mime>() else {
+        return false;
+    };
+
+    mime.essence_str() == mime::APPLICATION_WWW_FORM_URLENCODED.essence_str()
+}
+
+/// Turns a failure from [`to_bytes`] into the matching [`FormRejection`], distinguishing a
+/// genuine length-limit overrun (413) from any other body-read failure (e.g. a dropped
+/// connection or malformed chunked encoding), which is not the client sending too much data.
+fn classify_buffer_error(err: Error) -> FormRejection {
+    let err = err.into_inner();
+    if err.downcast_ref::<LengthLimitError>().is_some() {
+        FormRejection::PayloadTooLarge(Error::new(err))
+    } else {
+        FormRejection::FailedToBufferBody(Error::new(err))
+    }
+}
+
+/// Configuration for the [`Form`] extractor.
+///
+/// By default, [`Form`] reads the request through [`RawForm`]: axum's default body limit (see
+/// `DefaultBodyLimit`) applies, and a request other than `GET` must declare an
+/// `application/x-www-form-urlencoded` `Content-Type`. Insert a `FormConfig` into the request
+/// extensions (for example with [`Extension`](axum::Extension) as a layer) to opt into a
+/// body-size limit of its own and/or strict `Content-Type` checking. With a limit set, the body
+/// is read directly and its `Content-Type` is only checked when strict checking is on.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::{routing::post, Extension, Router};
+/// use axum_extra::extract::FormConfig;
+///
+/// let app: Router = Router::new()
+///     .route("/", post(|| async {}))
+///     .layer(Extension(FormConfig::new().limit(4096).content_type_required(true)));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg(feature = "form")]
+pub struct FormConfig {
+    limit: Option<usize>,
+    strict_content_type: bool,
+    json_rejections: bool,
+}
+
+impl FormConfig {
+    /// Create a new `FormConfig` with no body limit of its own and strict `Content-Type`
+    /// checking off, which leaves [`Form`] behaving as it does with no `FormConfig` at all.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of bytes to read from the request body.
+    ///
+    /// Requests whose body exceeds this limit are rejected with
+    /// [`FormRejection::PayloadTooLarge`].
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Require the request to declare an `application/x-www-form-urlencoded` `Content-Type`.
+    ///
+    /// Requests missing the header, or declaring a different content type, are rejected with
+    /// [`FormRejection::UnsupportedMediaType`].
+    pub fn content_type_required(mut self, required: bool) -> Self {
+        self.strict_content_type = required;
+        self
+    }
+
+    /// Render deserialization failures as a JSON body containing the offending field path, the
+    /// error message, and the status code, instead of the default plain-text body.
+    pub fn json_rejections(mut self, enabled: bool) -> Self {
+        self.json_rejections = enabled;
+        self
+    }
+}
+
+impl Default for FormConfig {
+    fn default() -> Self {
+        Self {
+            limit: None,
+            strict_content_type: false,
+            json_rejections: false,
+        }
+    }
+}
+
+impl<T> IntoResponse for Form<T>
+where
+    T: Serialize,
+{
+    fn into_response(self) -> Response {
+        match serde_html_form::to_string(&self.0) {
+            Ok(body) => (
+                [(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static(mime::APPLICATION_WWW_FORM_URLENCODED.as_ref()),
+                )],
+                body,
+            )
+                .into_response(),
+            Err(err) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                [(header::CONTENT_TYPE, HeaderValue::from_static(mime::TEXT_PLAIN_UTF_8.as_ref()))],
+                err.to_string(),
+            )
+                .into_response(),
+        }
     }
 }
 
@@ -74,7 +381,23 @@ pub enum FormRejection {
     #[allow(missing_docs)]
     RawFormRejection(RawFormRejection),
     #[allow(missing_docs)]
-    FailedToDeserializeForm(Error),
+    FailedToDeserializeForm {
+        /// The underlying deserialization error.
+        error: Error,
+        /// The dotted path to the field that failed to deserialize, if any.
+        path: String,
+        /// Whether this rejection should render as a structured JSON body, per
+        /// [`FormConfig::json_rejections`].
+        structured: bool,
+    },
+    #[allow(missing_docs)]
+    InvalidCharset(String),
+    #[allow(missing_docs)]
+    PayloadTooLarge(Error),
+    #[allow(missing_docs)]
+    FailedToBufferBody(Error),
+    #[allow(missing_docs)]
+    UnsupportedMediaType,
 }
 
 impl FormRejection {
@@ -83,7 +406,11 @@ impl FormRejection {
         // Make sure to keep this in sync with `IntoResponse` impl.
         match self {
             Self::RawFormRejection(inner) => inner.status(),
-            Self::FailedToDeserializeForm(_) => StatusCode::BAD_REQUEST,
+            Self::FailedToDeserializeForm { .. } => StatusCode::BAD_REQUEST,
+            Self::InvalidCharset(_) => StatusCode::BAD_REQUEST,
+            Self::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::FailedToBufferBody(_) => StatusCode::BAD_REQUEST,
+            Self::UnsupportedMediaType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
         }
     }
 }
@@ -93,8 +420,71 @@ impl IntoResponse for FormRejection {
         let status = self.status();
         match self {
             Self::RawFormRejection(inner) => inner.into_response(),
-            Self::FailedToDeserializeForm(inner) => {
-                let body = format!("Failed to deserialize form: {inner}");
+            Self::FailedToDeserializeForm {
+                error,
+                path,
+                structured,
+            } => {
+                if structured {
+                    let body = serde_json::json!({
+                        "path": path,
+                        "message": error.to_string(),
+                        "status": status.as_u16(),
+                    })
+                    .to_string();
+                    axum_core::__log_rejection!(
+                        rejection_type = Self,
+                        body_text = body,
+                        status = status,
+                    );
+                    (
+                        status,
+                        [(
+                            header::CONTENT_TYPE,
+                            HeaderValue::from_static(mime::APPLICATION_JSON.as_ref()),
+                        )],
+                        body,
+                    )
+                        .into_response()
+                } else {
+                    let body = format!("Failed to deserialize form: {error}");
+                    axum_core::__log_rejection!(
+                        rejection_type = Self,
+                        body_text = body,
+                        status = status,
+                    );
+                    (status, body).into_response()
+                }
+            }
+            Self::InvalidCharset(charset) => {
+                let body = format!("Failed to decode form: unknown charset `{charset}`");
+                axum_core::__log_rejection!(
+                    rejection_type = Self,
+                    body_text = body,
+                    status = status,
+                );
+                (status, body).into_response()
+            }
+            Self::PayloadTooLarge(inner) => {
+                let body = format!("Form body exceeds the configured length limit: {inner}");
+                axum_core::__log_rejection!(
+                    rejection_type = Self,
+                    body_text = body,
+                    status = status,
+                );
+                (status, body).into_response()
+            }
+            Self::FailedToBufferBody(inner) => {
+                let body = format!("Failed to buffer form body: {inner}");
+                axum_core::__log_rejection!(
+                    rejection_type = Self,
+                    body_text = body,
+                    status = status,
+                );
+                (status, body).into_response()
+            }
+            Self::UnsupportedMediaType => {
+                let body = "Content-Type must be `application/x-www-form-urlencoded`".to_owned();
                 axum_core::__log_rejection!(
                     rejection_type = Self,
                     body_text = body,
@@ -110,7 +500,13 @@ impl fmt::Display for FormRejection {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::RawFormRejection(inner) => inner.fmt(f),
-            Self::FailedToDeserializeForm(inner) => inner.fmt(f),
+            Self::FailedToDeserializeForm { error, .. } => error.fmt(f),
+            Self::InvalidCharset(charset) => write!(f, "unknown charset `{charset}`"),
+            Self::PayloadTooLarge(inner) => inner.fmt(f),
+            Self::FailedToBufferBody(inner) => inner.fmt(f),
+            Self::UnsupportedMediaType => {
+                write!(f, "Content-Type must be `application/x-www-form-urlencoded`")
+            }
         }
     }
 }
@@ -119,7 +515,11 @@ impl std::error::Error for FormRejection {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::RawFormRejection(inner) => Some(inner),
-            Self::FailedToDeserializeForm(inner) => Some(inner),
+            Self::FailedToDeserializeForm { error, .. } => Some(error),
+            Self::InvalidCharset(_) => None,
+            Self::PayloadTooLarge(inner) => Some(inner),
+            Self::FailedToBufferBody(inner) => Some(inner),
+            Self::UnsupportedMediaType => None,
         }
     }
 }
@@ -129,10 +529,10 @@ mod tests {
     use super::*;
     use crate::test_helpers::*;
     use axum::routing::{on, post, MethodFilter};
-    use axum::Router;
+    use axum::{Extension, Router};
     use http::header::CONTENT_TYPE;
     use mime::APPLICATION_WWW_FORM_URLENCODED;
-    use serde::Deserialize;
+    use serde::{Deserialize, Serialize};
 
     #[tokio::test]
     async fn supports_multiple_values() {
@@ -159,6 +559,261 @@ mod tests {
         assert_eq!(res.text().await, "one,two");
     }
 
+    #[tokio::test]
+    async fn response_sets_content_type_and_body() {
+        #[derive(Serialize)]
+        struct Data {
+            value: String,
+        }
+
+        let app = Router::new().route(
+            "/",
+            post(|| async move {
+                Form(Data {
+                    value: "one".to_owned(),
+                })
+            }),
+        );
+
+        let client = TestClient::new(app);
+
+        let res = client.post("/").await;
+
+        assert_eq!(
+            res.headers()[CONTENT_TYPE],
+            APPLICATION_WWW_FORM_URLENCODED.as_ref()
+        );
+        assert_eq!(res.text().await, "value=one");
+    }
+
+    #[tokio::test]
+    async fn decodes_non_utf8_charset() {
+        #[derive(Deserialize)]
+        struct Data {
+            value: String,
+        }
+
+        let app = Router::new().route(
+            "/",
+            post(|Form(data): Form<Data>| async move { data.value }),
+        );
+
+        let client = TestClient::new(app);
+
+        let (encoded, _, _) = encoding_rs::WINDOWS_1252.encode("caf\u{e9}");
+        let body: String = form_urlencoded::byte_serialize(&encoded).collect();
+        let body = format!("value={body}");
+
+        let res = client
+            .post("/")
+            .header(
+                CONTENT_TYPE,
+                "application/x-www-form-urlencoded; charset=windows-1252",
+            )
+            .body(body)
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.text().await, "caf\u{e9}");
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_charset() {
+        #[derive(Deserialize)]
+        #[allow(dead_code)]
+        struct Data {
+            value: String,
+        }
+
+        let app = Router::new().route("/", post(|_: Form<Data>| async {}));
+
+        let client = TestClient::new(app);
+
+        let res = client
+            .post("/")
+            .header(
+                CONTENT_TYPE,
+                "application/x-www-form-urlencoded; charset=bogus-charset",
+            )
+            .body("value=one")
+            .await;
+
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn rejects_body_over_configured_limit() {
+        #[derive(Deserialize)]
+        #[allow(dead_code)]
+        struct Data {
+            value: String,
+        }
+
+        let app = Router::new()
+            .route("/", post(|_: Form<Data>| async {}))
+            .layer(Extension(FormConfig::new().limit(4)));
+
+        let client = TestClient::new(app);
+
+        let res = client
+            .post("/")
+            .header(CONTENT_TYPE, APPLICATION_WWW_FORM_URLENCODED.as_ref())
+            .body("value=one")
+            .await;
+
+        assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn rejects_wrong_content_type_when_strict() {
+        #[derive(Deserialize)]
+        #[allow(dead_code)]
+        struct Data {
+            value: String,
+        }
+
+        let app = Router::new()
+            .route("/", post(|_: Form<Data>| async {}))
+            .layer(Extension(FormConfig::new().content_type_required(true)));
+
+        let client = TestClient::new(app);
+
+        let res = client
+            .post("/")
+            .header(CONTENT_TYPE, "application/json")
+            .body("value=one")
+            .await;
+
+        assert_eq!(res.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    #[tokio::test]
+    async fn renders_structured_json_rejection() {
+        #[allow(dead_code)]
+        #[derive(Deserialize)]
+        struct Payload {
+            a: i32,
+        }
+
+        let app = Router::new()
+            .route("/", post(|_: Form<Payload>| async {}))
+            .layer(Extension(FormConfig::new().json_rejections(true)));
+
+        let client = TestClient::new(app);
+
+        let res = client
+            .post("/")
+            .header(CONTENT_TYPE, APPLICATION_WWW_FORM_URLENCODED.as_ref())
+            .body("a=false")
+            .await;
+
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            res.headers()[CONTENT_TYPE],
+            mime::APPLICATION_JSON.as_ref()
+        );
+        let body: serde_json::Value = res.json().await;
+        assert_eq!(body["path"], "a");
+        assert_eq!(body["status"], 400);
+    }
+
+    #[tokio::test]
+    async fn optional_form_yields_none_for_empty_body() {
+        #[derive(Deserialize)]
+        struct Filters {
+            #[allow(dead_code)]
+            name: String,
+        }
+
+        let app = Router::new().route(
+            "/",
+            post(|OptionalForm(filters): OptionalForm<Filters>| async move {
+                filters.is_none().to_string()
+            }),
+        );
+
+        let client = TestClient::new(app);
+
+        let res = client.post("/").await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.text().await, "true");
+    }
+
+    #[tokio::test]
+    async fn optional_form_yields_none_for_chunked_empty_body() {
+        // A streamed body with no declared `Content-Length` reports `size_hint().exact() ==
+        // None`, unlike a body sent with `Content-Length: 0`. `OptionalForm` must still
+        // recognize it as empty.
+        #[derive(Deserialize)]
+        #[allow(dead_code)]
+        struct Filters {
+            name: String,
+        }
+
+        let body = axum::body::Body::from_stream(futures_util::stream::empty::<
+            Result<bytes::Bytes, std::io::Error>,
+        >());
+        let req = Request::builder()
+            .method("POST")
+            .uri("/")
+            .body(body)
+            .unwrap();
+
+        let OptionalForm(filters) = OptionalForm::<Filters>::from_request(req, &())
+            .await
+            .unwrap();
+
+        assert!(filters.is_none());
+    }
+
+    #[tokio::test]
+    async fn optional_form_deserializes_present_body() {
+        #[derive(Deserialize)]
+        struct Filters {
+            name: String,
+        }
+
+        let app = Router::new().route(
+            "/",
+            post(|OptionalForm(filters): OptionalForm<Filters>| async move {
+                filters.map(|f| f.name).unwrap_or_default()
+            }),
+        );
+
+        let client = TestClient::new(app);
+
+        let res = client
+            .post("/")
+            .header(CONTENT_TYPE, APPLICATION_WWW_FORM_URLENCODED.as_ref())
+            .body("name=filter")
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.text().await, "filter");
+    }
+
+    #[tokio::test]
+    async fn optional_form_rejects_malformed_body() {
+        #[derive(Deserialize)]
+        #[allow(dead_code)]
+        struct Filters {
+            name: String,
+        }
+
+        let app = Router::new().route("/", post(|_: OptionalForm<Filters>| async {}));
+
+        let client = TestClient::new(app);
+
+        let res = client
+            .post("/")
+            .header(CONTENT_TYPE, APPLICATION_WWW_FORM_URLENCODED.as_ref())
+            .body("not-a-valid-field-name")
+            .await;
+
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[tokio::test]
     async fn deserialize_error_status_codes() {
         #[allow(dead_code)]